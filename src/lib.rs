@@ -12,14 +12,81 @@ pub enum Exception<E = Unrecoverable> {
     #[error("A recoverable exception occured: {0}")]
     Unrecoverable(eyre::Report),
     #[error("An unrecoverable exception occured: {0}")]
-    Recoverable(E),
+    Recoverable(E, ExceptionExtensions),
+}
+
+/// Structured, machine-readable metadata attached to a recoverable exception.
+///
+/// Inspired by GraphQL-style `ServerError` extensions, this is an arbitrary map
+/// of string keys to [`serde_json::Value`]s that API boundaries can translate
+/// directly into a wire response.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ExceptionExtensions(std::collections::BTreeMap<String, serde_json::Value>);
+
+impl ExceptionExtensions {
+    /// Creates an empty extensions map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a key/value pair, returning `&mut self` for chaining.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> &mut Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the value stored under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+
+    /// Returns `true` if no metadata has been attached.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Serializes an [`Exception<E>`] into a wire-friendly shape.
+///
+/// A recoverable exception emits `{ "recoverable": <E>, "extensions": {..} }`,
+/// serializing the typed error via its own [`serde::Serialize`]. An unrecoverable
+/// one stays opaque, emitting only `{ "unrecoverable": <rendered report> }` — the
+/// rendered message and, when captured, its backtrace, but never structured fields.
+#[cfg(feature = "serde")]
+impl<E: serde::Serialize> serde::Serialize for Exception<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        match self {
+            Self::Recoverable(e, extensions) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("recoverable", e)?;
+                map.serialize_entry("extensions", extensions)?;
+                map.end()
+            }
+            Self::Unrecoverable(report) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("unrecoverable", &format!("{report:?}"))?;
+                map.end()
+            }
+        }
+    }
 }
 
 impl Exception<Unrecoverable> {
     pub fn into_unrecoverable(self) -> eyre::Report {
         match self {
             Self::Unrecoverable(e) => e,
-            Self::Recoverable(_) => unreachable!(),
+            Self::Recoverable(..) => unreachable!(),
         }
     }
 }
@@ -30,30 +97,66 @@ impl<E> Exception<E> {
     }
 
     pub fn new_unrecoverable(e: E) -> Self {
-        Self::Recoverable(e)
+        Self::Recoverable(e, ExceptionExtensions::new())
+    }
+
+    /// Attaches a structured-metadata entry to the recoverable arm, consuming `self`.
+    ///
+    /// On an [`Exception::Unrecoverable`] this is a no-op, as fatal reports stay
+    /// opaque and never carry structured fields.
+    pub fn with_extension(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.set_extension(key, value);
+        self
+    }
+
+    /// Attaches a structured-metadata entry to the recoverable arm in place.
+    ///
+    /// On an [`Exception::Unrecoverable`] this is a no-op, as fatal reports stay
+    /// opaque and never carry structured fields.
+    pub fn set_extension(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> &mut Self {
+        if let Self::Recoverable(_, extensions) = self {
+            extensions.insert(key, value);
+        }
+        self
+    }
+
+    /// Returns the structured metadata attached to the recoverable arm, if any.
+    pub fn extensions(&self) -> Option<&ExceptionExtensions> {
+        match self {
+            Self::Recoverable(_, extensions) => Some(extensions),
+            Self::Unrecoverable(_) => None,
+        }
     }
 
     pub fn is_recoverable(&self) -> bool {
-        matches!(self, Self::Recoverable(_))
+        matches!(self, Self::Recoverable(..))
     }
 
     pub fn try_as_recoverable(&self) -> eyre::Result<&E> {
         match self {
-            Self::Recoverable(e) => Ok(e),
+            Self::Recoverable(e, _) => Ok(e),
             _ => Err(eyre::eyre!("Not a specific error")),
         }
     }
 
     pub fn try_as_recoverable_mut(&mut self) -> eyre::Result<&mut E> {
         match self {
-            Self::Recoverable(e) => Ok(e),
+            Self::Recoverable(e, _) => Ok(e),
             _ => Err(eyre::eyre!("Not a specific error")),
         }
     }
 
     pub fn try_into_recoverable(self) -> Result<E, Self> {
         match self {
-            Self::Recoverable(e) => Ok(e),
+            Self::Recoverable(e, _) => Ok(e),
             e => Err(e),
         }
     }
@@ -83,7 +186,7 @@ impl<E> Exception<E> {
     pub fn split(self) -> (Option<eyre::Report>, Option<E>) {
         match self {
             Self::Unrecoverable(e) => (Some(e), None),
-            Self::Recoverable(e) => (None, Some(e)),
+            Self::Recoverable(e, _) => (None, Some(e)),
         }
     }
 
@@ -94,7 +197,7 @@ impl<E> Exception<E> {
     {
         match self {
             Self::Unrecoverable(e) => Exception::Unrecoverable(e),
-            Self::Recoverable(e) => Exception::Recoverable(f(e)),
+            Self::Recoverable(e, extensions) => Exception::Recoverable(f(e), extensions),
         }
     }
 
@@ -105,6 +208,36 @@ impl<E> Exception<E> {
     {
         self.map(Into::into)
     }
+
+    /// Runs `f`, turning any panic it raises into an [`Exception::Unrecoverable`].
+    ///
+    /// This bridges code that still panics — third-party libraries, arithmetic
+    /// overflow, slice indexing — into the exception world. The recoverable type
+    /// parameter stays generic so `catch` composes inside functions returning
+    /// [`ExceptionResult<T, E>`]. The captured panic message is preserved in the
+    /// report; a backtrace, when eyre captures one, is taken where the panic is
+    /// caught, not where it was raised.
+    pub fn catch<T, F>(f: F) -> ExceptionResult<T, E>
+    where
+        F: FnOnce() -> T + std::panic::UnwindSafe,
+    {
+        match std::panic::catch_unwind(f) {
+            Ok(value) => Ok(value),
+            Err(payload) => Err(Exception::Unrecoverable(panic_payload_to_report(payload))),
+        }
+    }
+}
+
+/// Downcasts a caught panic payload into an [`eyre::Report`], preserving the
+/// original `&str`/`String` message where one is available.
+fn panic_payload_to_report(payload: Box<dyn std::any::Any + Send>) -> eyre::Report {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        eyre::eyre!("panicked: {s}")
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        eyre::eyre!("panicked: {s}")
+    } else {
+        eyre::eyre!("panicked with a non-string payload")
+    }
 }
 
 /// Marks an error as recoverable, allowing it to be converted into an [`Exception<E>`]
@@ -123,7 +256,7 @@ where
     T: RecoverableError + Into<E>,
 {
     fn from(error: T) -> Self {
-        Exception::Recoverable(error.into())
+        Exception::Recoverable(error.into(), ExceptionExtensions::new())
     }
 }
 
@@ -134,7 +267,7 @@ where
     fn from(error: Exception) -> Self {
         match error {
             Exception::Unrecoverable(e) => Exception::Unrecoverable(e),
-            Exception::Recoverable(_) => unreachable!(),
+            Exception::Recoverable(..) => unreachable!(),
         }
     }
 }
@@ -150,6 +283,39 @@ impl<E> From<eyre::Report> for Exception<E> {
 #[error("unreachable")]
 pub enum Unrecoverable {}
 
+impl Unrecoverable {
+    /// Consumes this uninhabited value, producing any type.
+    ///
+    /// Because [`Unrecoverable`] has no variants, the `match` proves the code
+    /// unreachable at compile time with no runtime check — the total counterpart
+    /// to an `unreachable!()` or `.expect(..)` that might otherwise panic.
+    pub fn absurd<T>(self) -> T {
+        match self {}
+    }
+}
+
+impl From<std::convert::Infallible> for Unrecoverable {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl Exception<std::convert::Infallible> {
+    /// Collapses an exception whose recoverable arm is uninhabited into its report.
+    ///
+    /// Since [`Infallible`](std::convert::Infallible) can never be constructed,
+    /// only the [`Exception::Unrecoverable`] arm is reachable and its report is
+    /// returned as is. Separately, eyre's blanket `From<E: Error>` lets `?` turn an
+    /// `ExceptionResult<T, Infallible>` into `Result<T, eyre::Report>`; that path
+    /// wraps the whole exception in a new report and does not call this method.
+    pub fn into_report(self) -> eyre::Report {
+        match self {
+            Self::Unrecoverable(e) => e,
+            Self::Recoverable(e, _) => match e {},
+        }
+    }
+}
+
 pub type ExceptionResult<T, E = Unrecoverable> = Result<T, Exception<E>>;
 
 #[ext(ExceptionResultExt)]
@@ -170,6 +336,92 @@ pub impl<T, E> ExceptionResult<T, E> {
         self.map_exception(Into::into)
     }
 
+    /// Turns a [`Exception::Recoverable`] back into an `Ok` value.
+    ///
+    /// The [`Exception::Unrecoverable`] arm is left untouched so that it keeps
+    /// propagating — only the typed, recoverable error is handled in place.
+    #[inline]
+    fn recover<F>(self, f: F) -> ExceptionResult<T, E>
+    where
+        F: FnOnce(E) -> T,
+    {
+        match self {
+            Ok(t) => Ok(t),
+            Err(Exception::Recoverable(e, _)) => Ok(f(e)),
+            Err(Exception::Unrecoverable(e)) => Err(Exception::Unrecoverable(e)),
+        }
+    }
+
+    /// Swaps the recoverable error out for another exception-producing computation.
+    ///
+    /// As with [`recover`](ExceptionResultExt::recover), the unrecoverable arm is
+    /// never touched and always stays on the error path.
+    ///
+    /// Named `recover_with` rather than `or_else` to avoid being shadowed by the
+    /// inherent [`Result::or_else`], whose closure receives the whole exception.
+    #[inline]
+    fn recover_with<E2, F>(self, f: F) -> ExceptionResult<T, E2>
+    where
+        F: FnOnce(E) -> ExceptionResult<T, E2>,
+    {
+        match self {
+            Ok(t) => Ok(t),
+            Err(Exception::Recoverable(e, _)) => f(e),
+            Err(Exception::Unrecoverable(e)) => Err(Exception::Unrecoverable(e)),
+        }
+    }
+
+    /// Runs a fallible closure, converting any panic it raises into an
+    /// [`Exception::Unrecoverable`] and flattening the nested result.
+    ///
+    /// This is the [`ExceptionResult`] counterpart to [`Exception::catch`], for
+    /// wrapping a closure that already returns an [`ExceptionResult<T, E>`].
+    #[inline]
+    fn catch_panics<F>(f: F) -> ExceptionResult<T, E>
+    where
+        F: FnOnce() -> ExceptionResult<T, E> + std::panic::UnwindSafe,
+    {
+        match Exception::catch(f) {
+            Ok(result) => result,
+            Err(panic) => Err(panic),
+        }
+    }
+
+    /// Attaches eyre context to the [`Exception::Unrecoverable`] arm only.
+    ///
+    /// The typed [`Exception::Recoverable`] error is left bit-for-bit unchanged,
+    /// so it can still be pattern-matched downstream while fatal failures gain
+    /// eyre's chained-context reporting.
+    ///
+    /// Named `unrecoverable_context` rather than `context` so it does not become
+    /// ambiguous with eyre's `Context`/`WrapErr` trait when that is also in scope.
+    #[inline]
+    fn unrecoverable_context<C>(self, context: C) -> ExceptionResult<T, E>
+    where
+        C: std::fmt::Display + Send + Sync + 'static,
+    {
+        self.map_err(|e| match e {
+            Exception::Unrecoverable(report) => Exception::Unrecoverable(report.wrap_err(context)),
+            Exception::Recoverable(e, extensions) => Exception::Recoverable(e, extensions),
+        })
+    }
+
+    /// Lazily attaches eyre context to the [`Exception::Unrecoverable`] arm only.
+    ///
+    /// Like [`unrecoverable_context`](ExceptionResultExt::unrecoverable_context),
+    /// but the context is only built when a fatal error is actually present.
+    #[inline]
+    fn with_unrecoverable_context<C, F>(self, f: F) -> ExceptionResult<T, E>
+    where
+        C: std::fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| match e {
+            Exception::Unrecoverable(report) => Exception::Unrecoverable(report.wrap_err(f())),
+            Exception::Recoverable(e, extensions) => Exception::Recoverable(e, extensions),
+        })
+    }
+
     /// Splits the [`ExceptionResult<T, E>`] into a `Result<Result<T, E>, eyre::Report>`.
     ///
     /// This allows for easy propagation of unrecoverable errors.
@@ -179,7 +431,7 @@ pub impl<T, E> ExceptionResult<T, E> {
             Ok(t) => Ok(Ok(t)),
             Err(e) => match e {
                 Exception::Unrecoverable(e) => Err(e),
-                Exception::Recoverable(e) => Ok(Err(e)),
+                Exception::Recoverable(e, _) => Ok(Err(e)),
             },
         }
     }
@@ -196,11 +448,26 @@ pub impl<T> ExceptionResult<T> {
     }
 }
 
+#[ext(UnrecoverableResultExt)]
+pub impl<T> Result<T, Unrecoverable> {
+    /// Unwraps a `Result<T, Unrecoverable>` without the possibility of a panic.
+    ///
+    /// The `Err` arm is consumed through [`Unrecoverable::absurd`], so the
+    /// compiler proves it unreachable rather than relying on a runtime check.
+    #[inline]
+    fn unwrap_unrecoverable(self) -> T {
+        match self {
+            Ok(t) => t,
+            Err(e) => e.absurd(),
+        }
+    }
+}
+
 #[ext(ResultExt)]
 pub impl<T, E> Result<T, E> {
     #[inline]
     fn recoverable(self) -> ExceptionResult<T, E> {
-        self.map_err(Exception::Recoverable)
+        self.map_err(|e| Exception::Recoverable(e, ExceptionExtensions::new()))
     }
 
     #[inline]
@@ -230,6 +497,9 @@ impl Finalize for Unrecoverable {
     type Output<T> = T;
 
     fn finalize<T>(res: Result<T, Unrecoverable>) -> T {
-        res.expect("NoCustomBackendError can't be created")
+        match res {
+            Ok(t) => t,
+            Err(e) => e.absurd(),
+        }
     }
 }
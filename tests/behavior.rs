@@ -0,0 +1,113 @@
+use exception::{
+    Exception, ExceptionResult, ExceptionResultExt, ResultExt, Unrecoverable,
+    UnrecoverableResultExt,
+};
+
+#[derive(Debug, thiserror::Error)]
+#[error("MyError: {0}")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct MyError(String);
+
+fn recoverable(msg: &str) -> ExceptionResult<i32, MyError> {
+    Err(MyError(msg.to_owned())).recoverable()
+}
+
+fn unrecoverable(msg: &str) -> ExceptionResult<i32, MyError> {
+    Err(Exception::Unrecoverable(eyre::eyre!("{msg}")))
+}
+
+#[test]
+fn recover_handles_recoverable_but_leaves_fatal() {
+    assert_eq!(recoverable("boom").recover(|_| 42).unwrap(), 42);
+    assert!(unrecoverable("boom").recover(|_| 42).is_err());
+}
+
+#[test]
+fn recover_with_swaps_the_recoverable_error() {
+    let swapped = recoverable("boom").recover_with(|_| -> ExceptionResult<i32, MyError> { Ok(7) });
+    assert_eq!(swapped.unwrap(), 7);
+    assert!(unrecoverable("boom")
+        .recover_with(|_| -> ExceptionResult<i32, MyError> { Ok(7) })
+        .is_err());
+}
+
+#[test]
+fn unrecoverable_context_decorates_only_the_fatal_arm() {
+    // The recoverable arm is passed through untouched.
+    assert!(matches!(
+        recoverable("boom").unrecoverable_context("while loading config"),
+        Err(Exception::Recoverable(..))
+    ));
+
+    // The unrecoverable arm gains eyre's chained context.
+    match unrecoverable("root cause").unrecoverable_context("while loading config") {
+        Err(Exception::Unrecoverable(report)) => {
+            let rendered = format!("{report:?}");
+            assert!(rendered.contains("while loading config"));
+            assert!(rendered.contains("root cause"));
+        }
+        other => panic!("expected unrecoverable, got {other:?}"),
+    }
+}
+
+#[test]
+fn catch_turns_panics_into_unrecoverable_reports() {
+    let ok: ExceptionResult<i32> = Exception::catch(|| 1 + 2);
+    assert_eq!(ok.unwrap(), 3);
+
+    let caught: ExceptionResult<i32> = Exception::catch(|| panic!("kaboom"));
+    match caught {
+        Err(Exception::Unrecoverable(report)) => {
+            assert!(format!("{report}").contains("kaboom"));
+        }
+        other => panic!("expected unrecoverable, got {other:?}"),
+    }
+}
+
+#[test]
+fn catch_panics_flattens_the_nested_result() {
+    let caught = <ExceptionResult<i32, MyError> as ExceptionResultExt<i32, MyError>>::catch_panics(
+        || panic!("inner"),
+    );
+    assert!(matches!(caught, Err(Exception::Unrecoverable(_))));
+}
+
+#[test]
+fn unwrap_unrecoverable_needs_no_panic() {
+    let res: Result<i32, Unrecoverable> = Ok(10);
+    assert_eq!(res.unwrap_unrecoverable(), 10);
+}
+
+#[test]
+fn infallible_collapses_into_eyre_report() {
+    fn collapse() -> eyre::Result<i32> {
+        let res: ExceptionResult<i32, std::convert::Infallible> = Ok(3);
+        Ok(res?)
+    }
+    assert_eq!(collapse().unwrap(), 3);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn recoverable_serializes_with_extensions() {
+    let exception = Exception::new_unrecoverable(MyError("boom".to_owned()))
+        .with_extension("code", 42)
+        .with_extension("retryable", false);
+
+    let json = serde_json::to_value(&exception).unwrap();
+    // A newtype struct serializes as its inner value, not its `Display` form.
+    assert_eq!(json["recoverable"], serde_json::json!("boom"));
+    assert_eq!(json["extensions"]["code"], serde_json::json!(42));
+    assert_eq!(json["extensions"]["retryable"], serde_json::json!(false));
+    assert!(json.get("unrecoverable").is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn unrecoverable_serializes_opaquely() {
+    let exception: Exception<MyError> = Exception::Unrecoverable(eyre::eyre!("fatal"));
+    let json = serde_json::to_value(&exception).unwrap();
+    assert!(json["unrecoverable"].as_str().unwrap().contains("fatal"));
+    assert!(json.get("recoverable").is_none());
+    assert!(json.get("extensions").is_none());
+}